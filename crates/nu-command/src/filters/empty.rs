@@ -1,4 +1,4 @@
-use nu_engine::CallExt;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::{Call, CellPath};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
@@ -20,6 +20,22 @@ impl Command for Empty {
                 SyntaxShape::CellPath,
                 "the names of the columns to check emptiness",
             )
+            .named(
+                "value",
+                SyntaxShape::Any,
+                "a value (or block returning a value) to fill empty cells with, instead of just reporting emptiness",
+                Some('v'),
+            )
+            .switch(
+                "report",
+                "emit a table with one boolean column per requested path, instead of collapsing to a single result",
+                Some('r'),
+            )
+            .switch(
+                "strict",
+                "also treat whitespace-only strings and zero-valued ints, durations and filesizes as empty",
+                Some('s'),
+            )
             .category(Category::Filters)
     }
 
@@ -63,6 +79,36 @@ impl Command for Empty {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Fill empty cells in a column with a default value",
+                example: "[[name]; [''] [sacha]] | empty? name --value 'N/A' | get name.0",
+                result: Some(Value::String {
+                    val: "N/A".to_string(),
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Fill empty cells with the result of a block, evaluated per row",
+                example: "[[name]; [''] [sacha]] | empty? name --value { 0 }",
+                result: None,
+            },
+            Example {
+                description: "See which of several columns are empty, row by row",
+                example:
+                    "[[meal size]; [arepa small] [taco '']] | empty? meal size --report | get size.1",
+                result: Some(Value::Bool {
+                    val: true,
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Also treat whitespace-only strings and zero as empty",
+                example: "'   ' | empty? --strict",
+                result: Some(Value::Bool {
+                    val: true,
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
@@ -75,21 +121,30 @@ fn empty(
 ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
     let head = call.head;
     let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+    let replacement: Option<Value> = call.get_flag(engine_state, stack, "value")?;
+    let report = call.has_flag("report");
+    let strict = call.has_flag("strict");
+
+    check_flag_conflicts(replacement.is_some(), report, &columns, head)?;
+
+    if let Some(replacement) = replacement {
+        return fill_empty(engine_state, stack, head, input, columns, replacement, strict);
+    }
+
+    if report {
+        return build_report(engine_state, input, &columns, head, strict);
+    }
 
     if !columns.is_empty() {
         for val in input {
             for column in &columns {
                 let val = val.clone();
-                match val.follow_cell_path(&column.members) {
-                    Ok(Value::Nothing { .. }) => {}
-                    Ok(_) => {
-                        return Ok(Value::Bool {
-                            val: false,
-                            span: head,
-                        }
-                        .into_pipeline_data())
+                if !is_empty(val.follow_cell_path(&column.members)?, strict) {
+                    return Ok(Value::Bool {
+                        val: false,
+                        span: head,
                     }
-                    Err(err) => return Err(err),
+                    .into_pipeline_data());
                 }
             }
         }
@@ -126,7 +181,7 @@ fn empty(
             }
             .into_pipeline_data()),
             PipelineData::Value(value, ..) => {
-                let answer = is_empty(value);
+                let answer = is_empty(value, strict);
 
                 Ok(Value::Bool {
                     val: answer,
@@ -138,13 +193,200 @@ fn empty(
     }
 }
 
-pub fn is_empty(value: Value) -> bool {
+/// `--value` and `--report` are mutually exclusive, and `--report` needs at least one cell path
+/// to report on. Kept as its own function so the dispatch decision in `empty()` can be unit
+/// tested without going through a full `Call`.
+fn check_flag_conflicts(
+    has_replacement: bool,
+    report: bool,
+    columns: &[CellPath],
+    head: Span,
+) -> Result<(), nu_protocol::ShellError> {
+    if has_replacement && report {
+        return Err(nu_protocol::ShellError::IncompatibleParametersSingle(
+            "--value and --report cannot be used together".into(),
+            head,
+        ));
+    }
+
+    if report && columns.is_empty() {
+        return Err(nu_protocol::ShellError::IncompatibleParametersSingle(
+            "--report requires at least one cell path to check".into(),
+            head,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Stream the input back out as one record per row, with a boolean column per requested path
+/// reporting whether that cell was empty, preserving the cell's own span for error locations.
+fn build_report(
+    engine_state: &EngineState,
+    input: PipelineData,
+    columns: &[CellPath],
+    head: Span,
+    strict: bool,
+) -> Result<PipelineData, nu_protocol::ShellError> {
+    let ctrlc = engine_state.ctrlc.clone();
+    let columns = columns.to_vec();
+
+    input.map(
+        move |row| match report_row(&row, &columns, head, strict) {
+            Ok(value) => value,
+            Err(error) => Value::Error { error },
+        },
+        ctrlc,
+    )
+}
+
+fn report_row(
+    row: &Value,
+    columns: &[CellPath],
+    head: Span,
+    strict: bool,
+) -> Result<Value, nu_protocol::ShellError> {
+    let mut cols = Vec::with_capacity(columns.len());
+    let mut vals = Vec::with_capacity(columns.len());
+
+    for column in columns {
+        let cell = row.follow_cell_path(&column.members)?;
+        let span = cell.span().unwrap_or(head);
+
+        cols.push(column.to_string());
+        vals.push(Value::Bool {
+            val: is_empty(cell, strict),
+            span,
+        });
+    }
+
+    Ok(Value::Record {
+        cols,
+        vals,
+        span: row.span().unwrap_or(head),
+    })
+}
+
+/// Stream the input back out, replacing any empty cell at `columns` (or the whole value, if
+/// `columns` is empty) with `replacement`. If `replacement` is a block, it is evaluated once per
+/// row to produce the fill-in value.
+fn fill_empty(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    head: Span,
+    input: PipelineData,
+    columns: Vec<CellPath>,
+    replacement: Value,
+    strict: bool,
+) -> Result<PipelineData, nu_protocol::ShellError> {
+    let ctrlc = engine_state.ctrlc.clone();
+    let engine_state = engine_state.clone();
+    let mut stack = stack.clone();
+
+    input.map(
+        move |value| {
+            match fill_row(
+                &engine_state,
+                &mut stack,
+                head,
+                value,
+                &columns,
+                &replacement,
+                strict,
+            ) {
+                Ok(value) => value,
+                Err(error) => Value::Error { error },
+            }
+        },
+        ctrlc,
+    )
+}
+
+fn fill_row(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    head: Span,
+    row: Value,
+    columns: &[CellPath],
+    replacement: &Value,
+    strict: bool,
+) -> Result<Value, nu_protocol::ShellError> {
+    if columns.is_empty() {
+        return if is_empty(row.clone(), strict) {
+            eval_replacement(engine_state, stack, head, replacement, &row)
+        } else {
+            Ok(row)
+        };
+    }
+
+    let mut empty_columns = Vec::new();
+    for column in columns {
+        if is_empty(row.follow_cell_path(&column.members)?, strict) {
+            empty_columns.push(column);
+        }
+    }
+
+    if empty_columns.is_empty() {
+        return Ok(row);
+    }
+
+    // Evaluate the replacement once per row, against the original row, so a block with side
+    // effects (e.g. a counter or `random int`) behaves the same no matter how many columns in
+    // this row happen to be empty, and sees the same row for each of them.
+    let fill = eval_replacement(engine_state, stack, head, replacement, &row)?;
+
+    let mut row = row;
+    for column in empty_columns {
+        let fill = fill.clone();
+        row.update_cell_path(&column.members, Box::new(move |_| fill))?;
+    }
+
+    Ok(row)
+}
+
+fn eval_replacement(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    head: Span,
+    replacement: &Value,
+    row: &Value,
+) -> Result<Value, nu_protocol::ShellError> {
+    match replacement {
+        Value::Block { val: block_id, .. } => {
+            let block = engine_state.get_block(*block_id);
+            let result = eval_block(
+                engine_state,
+                stack,
+                block,
+                row.clone().into_pipeline_data(),
+                false,
+                false,
+            )?;
+
+            result.into_value(head)
+        }
+        other => Ok(other.clone()),
+    }
+}
+
+/// Check whether `value` counts as empty. In `strict` mode, whitespace-only strings count as
+/// empty too, along with zero-valued ints, durations and filesizes.
+pub fn is_empty(value: Value, strict: bool) -> bool {
     match value {
         Value::List { vals, .. } => vals.is_empty(),
-        Value::String { val, .. } => val.is_empty(),
+        Value::String { val, .. } => {
+            if strict {
+                val.trim().is_empty()
+            } else {
+                val.is_empty()
+            }
+        }
         Value::Binary { val, .. } => val.is_empty(),
         Value::Nothing { .. } => true,
         Value::Record { cols, .. } => cols.is_empty(),
+        Value::Int { val, .. } if strict => val == 0,
+        Value::Duration { val, .. } if strict => val == 0,
+        Value::Filesize { val, .. } if strict => val == 0,
         _ => false,
     }
 }
@@ -152,6 +394,8 @@ pub fn is_empty(value: Value) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nu_protocol::ast::PathMember;
+    use nu_protocol::engine::EngineState;
 
     #[test]
     fn test_examples() {
@@ -159,4 +403,130 @@ mod tests {
 
         test_examples(Empty {})
     }
+
+    fn cell_path(name: &str) -> CellPath {
+        CellPath {
+            members: vec![PathMember::String {
+                val: name.to_string(),
+                span: Span::test_data(),
+            }],
+        }
+    }
+
+    fn record(cols: &[&str], vals: Vec<Value>) -> Value {
+        Value::Record {
+            cols: cols.iter().map(|c| c.to_string()).collect(),
+            vals,
+            span: Span::test_data(),
+        }
+    }
+
+    fn string(val: &str) -> Value {
+        Value::String {
+            val: val.to_string(),
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn test_is_empty_strict_trims_whitespace() {
+        assert!(!is_empty(string("  "), false));
+        assert!(is_empty(string("  "), true));
+    }
+
+    #[test]
+    fn test_is_empty_strict_treats_zero_as_empty() {
+        let zero = Value::Int {
+            val: 0,
+            span: Span::test_data(),
+        };
+        let nonzero = Value::Int {
+            val: 1,
+            span: Span::test_data(),
+        };
+
+        assert!(!is_empty(zero.clone(), false));
+        assert!(is_empty(zero, true));
+        assert!(!is_empty(nonzero, true));
+    }
+
+    #[test]
+    fn test_check_flag_conflicts_rejects_value_and_report_together() {
+        let columns = vec![cell_path("name")];
+
+        assert!(check_flag_conflicts(true, true, &columns, Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn test_check_flag_conflicts_rejects_report_without_columns() {
+        assert!(check_flag_conflicts(false, true, &[], Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn test_check_flag_conflicts_allows_report_with_columns() {
+        let columns = vec![cell_path("name")];
+
+        assert!(check_flag_conflicts(false, true, &columns, Span::test_data()).is_ok());
+    }
+
+    #[test]
+    fn test_report_row_reports_one_bool_per_column() {
+        let row = record(&["meal", "size"], vec![string("taco"), string("")]);
+        let columns = vec![cell_path("meal"), cell_path("size")];
+
+        let reported = report_row(&row, &columns, Span::test_data(), false).unwrap();
+
+        match reported {
+            Value::Record { cols, vals, .. } => {
+                assert_eq!(cols, vec!["meal".to_string(), "size".to_string()]);
+                assert_eq!(
+                    vals,
+                    vec![
+                        Value::Bool {
+                            val: false,
+                            span: Span::test_data()
+                        },
+                        Value::Bool {
+                            val: true,
+                            span: Span::test_data()
+                        },
+                    ]
+                );
+            }
+            other => panic!("expected a record, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_fill_row_applies_same_replacement_to_every_empty_column() {
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+        let row = record(&["first", "second", "third"], vec![string(""), string(""), string("b")]);
+        let columns = vec![cell_path("first"), cell_path("second"), cell_path("third")];
+        let replacement = string("filled");
+
+        let filled = fill_row(
+            &engine_state,
+            &mut stack,
+            Span::test_data(),
+            row,
+            &columns,
+            &replacement,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            filled.follow_cell_path(&columns[0].members).unwrap(),
+            string("filled")
+        );
+        assert_eq!(
+            filled.follow_cell_path(&columns[1].members).unwrap(),
+            string("filled")
+        );
+        assert_eq!(
+            filled.follow_cell_path(&columns[2].members).unwrap(),
+            string("b")
+        );
+    }
 }